@@ -0,0 +1,22 @@
+//! Thin wrappers around [`std::env`]/[`dirs_sys`] that normalize results to
+//! UTF-8 `String`s, since the crate only supports UTF-8 paths.
+
+use anyhow::{anyhow, Context, Result};
+
+pub(crate) fn current_dir() -> Result<String> {
+    to_utf8(std::env::current_dir().context("could not resolve the current working directory")?)
+}
+
+pub(crate) fn home_dir() -> Result<String> {
+    to_utf8(dirs_sys::home_dir().ok_or_else(|| anyhow!("could not resolve the home directory"))?)
+}
+
+pub(crate) fn env_var(key: &str) -> Result<String> {
+    std::env::var(key).with_context(|| format!("environment variable not set: {key}"))
+}
+
+fn to_utf8(path: std::path::PathBuf) -> Result<String> {
+    path.into_os_string()
+        .into_string()
+        .map_err(|raw| anyhow!("path is not valid UTF-8: {}", raw.to_string_lossy()))
+}
@@ -0,0 +1,17 @@
+//! Small `char` helpers shared by the environment-variable expander and
+//! path resolution.
+
+pub(crate) trait CharExt {
+    fn is_slash(self) -> bool;
+    fn is_allowed_in_environment_var(self) -> bool;
+}
+
+impl CharExt for char {
+    fn is_slash(self) -> bool {
+        self == '/' || self == '\\'
+    }
+
+    fn is_allowed_in_environment_var(self) -> bool {
+        self.is_ascii_alphanumeric() || self == '_'
+    }
+}
@@ -0,0 +1,106 @@
+mod envs;
+pub(crate) mod str_values;
+mod traits;
+
+use std::marker::PhantomData;
+
+use anyhow::{ensure, Result};
+
+use crate::iter::InnerSegmentIter;
+use crate::os::{Native, OsGroup};
+use crate::win_prefix::{non_windows_root_segment, parse_windows_prefix, render_windows_root};
+use crate::SLASH;
+
+use self::envs::{contract_envs, expand_envs};
+
+/// The in-memory representation shared by every typed path wrapper
+/// (`AnyDir`, `AbsDir`, ...). Resolution (environment variables, `~`/`.`
+/// prefixes, Windows prefixes, `..`/`.` collapsing) happens once, here, when
+/// the path is constructed; the wrappers only add type-level constraints
+/// (absolute vs relative, directory vs file) on top.
+pub struct PathInner<OS: OsGroup = Native> {
+    pub(crate) path: String,
+    _os: PhantomData<OS>,
+}
+
+impl<OS: OsGroup> Clone for PathInner<OS> {
+    fn clone(&self) -> Self {
+        Self {
+            path: self.path.clone(),
+            _os: PhantomData,
+        }
+    }
+}
+
+impl<OS: OsGroup> PathInner<OS> {
+    pub(crate) fn new(input: &str) -> Result<Self> {
+        let expanded = expand_envs(input)?;
+
+        let (root, rest) = if let Some((prefix, rest)) = parse_windows_prefix(&expanded) {
+            if OS::SEP == '\\' {
+                (Some(render_windows_root(&prefix, rest, OS::SEP)), rest)
+            } else {
+                (non_windows_root_segment(&prefix), rest)
+            }
+        } else if let Some(rest) = expanded.strip_prefix(SLASH) {
+            (Some(OS::SEP.to_string()), rest)
+        } else {
+            (None, expanded.as_ref())
+        };
+
+        let mut segments: Vec<&str> = Vec::new();
+        for (seg, _) in InnerSegmentIter::new(rest) {
+            ensure!(
+                seg.len() <= 255,
+                "path component is too long (max 255 characters): {seg}"
+            );
+            ensure!(
+                !seg.contains(['\0', ':']),
+                "path contains a forbidden character: {input}"
+            );
+            segments.push(seg);
+        }
+
+        let joined = segments.join(&OS::SEP.to_string());
+        let mut path = root.unwrap_or_default();
+        if !path.is_empty() && !joined.is_empty() && !path.ends_with(OS::SEP) {
+            path.push(OS::SEP);
+        }
+        path.push_str(&joined);
+
+        Ok(Self {
+            path,
+            _os: PhantomData,
+        })
+    }
+
+    pub(crate) fn new_from_path(value: &std::path::Path) -> Result<Self> {
+        let s = value
+            .to_str()
+            .ok_or_else(|| anyhow::anyhow!("path is not valid UTF-8: {}", value.display()))?;
+        Self::new(s)
+    }
+
+    /// The segments of the path, in order, after `.`/`..` collapsing.
+    pub(crate) fn segments(&self) -> impl Iterator<Item = &str> {
+        InnerSegmentIter::new(&self.path).map(|(s, _)| s)
+    }
+
+    pub(crate) fn is_absolute(&self) -> bool {
+        self.path.starts_with(SLASH)
+            || (self.path.chars().next().is_some_and(|c| c.is_ascii_alphabetic())
+                && self.path.chars().nth(1) == Some(':'))
+    }
+
+    /// Contracts the home/cwd prefix to `~`/`.` when `contract` is set,
+    /// falling back to the full resolved path otherwise or on failure to
+    /// resolve the home/current directory.
+    pub(crate) fn as_contracted(&self, contract: bool) -> (Option<char>, &str) {
+        if contract {
+            if let Ok((chr, path)) = contract_envs(&self.path) {
+                return (chr, path);
+            }
+        }
+        (None, &self.path)
+    }
+}
@@ -1,10 +1,13 @@
 use std::{
+    cmp::Ordering,
     fmt::{Debug, Display},
+    hash::{Hash, Hasher},
     path::Path,
 };
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
+use crate::iter::InnerSegmentIter;
 use crate::os::OsGroup;
 
 use super::PathInner;
@@ -32,6 +35,53 @@ impl<OS: OsGroup> Debug for PathInner<OS> {
     }
 }
 
+impl<OS: OsGroup> PathInner<OS> {
+    /// The case-folded (Unicode lowercasing via `char::to_lowercase`)
+    /// comparison key: the root prefix followed by the path's
+    /// segments, so that equality, ordering and hashing all agree with the
+    /// crate's case-insensitive comparison semantics. The root is included
+    /// so that an absolute path and a relative path sharing the same
+    /// segments (e.g. `/foo/bar` and `foo/bar`) never compare equal. Drive
+    /// letters are already lower-cased, so they fold for free.
+    fn fold_key(&self) -> Vec<String> {
+        std::iter::once(crate::ops::fold_segment(self.root_prefix()))
+            .chain(InnerSegmentIter::new(&self.path).map(|(seg, _)| crate::ops::fold_segment(seg)))
+            .collect()
+    }
+}
+
+impl<OS: OsGroup> PartialEq for PathInner<OS> {
+    fn eq(&self, other: &Self) -> bool {
+        self.fold_key() == other.fold_key()
+    }
+}
+
+impl<OS: OsGroup> Eq for PathInner<OS> {}
+
+impl<OS: OsGroup> PartialOrd for PathInner<OS> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<OS: OsGroup> Ord for PathInner<OS> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.fold_key().cmp(&other.fold_key())
+    }
+}
+
+impl<OS: OsGroup> Hash for PathInner<OS> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Hash each segment individually (rather than the joined string) so
+        // that two paths only hash equally when `eq` also agrees, e.g.
+        // `["ab", "c"]` must not collide with `["a", "bc"]`.
+        for seg in self.fold_key() {
+            seg.hash(state);
+            0u8.hash(state);
+        }
+    }
+}
+
 pub trait TryExist<T>: Sized {
     /// Performs the conversion.
     fn try_exist(value: T) -> anyhow::Result<Self>;
@@ -45,3 +95,13 @@ impl<OS: OsGroup> Serialize for PathInner<OS> {
         ser.serialize_str(&format!("{self:?}"))
     }
 }
+
+impl<'de, OS: OsGroup> Deserialize<'de> for PathInner<OS> {
+    fn deserialize<D>(de: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let raw = String::deserialize(de)?;
+        PathInner::new(&raw).map_err(serde::de::Error::custom)
+    }
+}
@@ -93,6 +93,9 @@
 //! | `dir/..`                 |                                          |                    |                                          | Empty path
 //! | `dir1/dir2/..`           | `dir1`                                   |                    |                                          |
 //! | `${MYDIR}`,<br>`%MYDIR%` | `dir`                                    | var("MYDIR")       | `dir`                                    | See [Environment variables](#environment-variables)
+//! | `\\server\share\dir`     | nix: `/server/share/dir`<br>win: `\\server\share\dir` |      |                                          | UNC path, always absolute
+//! | `\\?\C:\dir`             | nix: `/dir`<br>win: `c:\dir`        |                    |                                          | Verbatim disk path, legacy form preferred on output
+//! | `\\?\UNC\server\share\dir` | nix: `/server/share/dir`<br>win: `\\server\share\dir` |      |                                          | Verbatim UNC path, legacy form preferred on output
 //!
 //! Legend:
 //! - <sup>*</sup> - Any `/` can also be `\`.
@@ -133,15 +136,23 @@
 //! - [Naming Files, Paths, and Namespaces](https://learn.microsoft.com/en-us/windows/win32/fileio/naming-a-file)
 //! - [Wikipedia: Filenames - Comparison of filename limitations](https://en.wikipedia.org/wiki/Filename#Comparison_of_filename_limitations)
 
+mod env;
 mod ext;
 mod inner;
 mod iter;
+mod macros;
+mod ops;
+mod os;
 mod path;
+mod path_list;
+mod win_prefix;
 
 const SEP: char = std::path::MAIN_SEPARATOR;
 const SLASH: [char; 2] = ['/', '\\'];
 
-pub use path::AnyPath;
+pub use ops::Joined;
+pub use path::{AbsDir, AbsFile, AnyDir, AnyFile, AnyPath, RelPath};
+pub use path_list::{join_paths, split_paths, EmptyEntries};
 
 #[cfg(test)]
 #[test]
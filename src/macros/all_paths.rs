@@ -0,0 +1,161 @@
+/// Implements the read-only surface shared by every typed path wrapper:
+/// `segments()`, `Debug` (`Struct("resolved/path")`), and `AsRef<Path>`.
+#[macro_export]
+macro_rules! all_paths {
+    ($struct:ident) => {
+        impl $struct {
+            /// The segments of the path, in order, after `.`/`..` collapsing.
+            pub fn segments(&self) -> impl Iterator<Item = &str> {
+                self.0.segments()
+            }
+        }
+
+        impl std::fmt::Debug for $struct {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}(", stringify!($struct))?;
+                std::fmt::Debug::fmt(&self.0, f)?;
+                write!(f, ")")
+            }
+        }
+
+        impl AsRef<std::path::Path> for $struct {
+            fn as_ref(&self) -> &std::path::Path {
+                self.0.as_ref()
+            }
+        }
+    };
+}
+
+/// Implements the directory-typed manipulation surface: `parent`,
+/// `file_name`, `file_stem`, `extension`, `with_extension`,
+/// `with_file_name`, `push`, a `join` that returns `$dir` or `$file`
+/// depending on whether the appended segment has an extension, and a
+/// `Display` that renders a trailing separator — "this is a directory" is
+/// a property of the type applying the macro, decided once here, rather
+/// than something every directory-typed wrapper has to reimplement (or
+/// forgets to).
+#[macro_export]
+macro_rules! dir_path {
+    ($dir:ident, $file:ident) => {
+        impl std::fmt::Display for $dir {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let rendered = if f.alternate() {
+                    format!("{:#}", self.0)
+                } else {
+                    format!("{}", self.0)
+                };
+                f.write_str(&rendered)?;
+                if !rendered.ends_with(crate::SEP) {
+                    write!(f, "{}", crate::SEP)?;
+                }
+                Ok(())
+            }
+        }
+
+        impl $dir {
+            /// The enclosing directory, or `None` if this path has no parent segment.
+            pub fn parent(&self) -> Option<$dir> {
+                self.0.parent().and_then(|p| $dir(p).validate().ok())
+            }
+
+            /// The final segment of the path, if any.
+            pub fn file_name(&self) -> Option<&str> {
+                self.0.file_name()
+            }
+
+            /// The final segment without its extension.
+            pub fn file_stem(&self) -> Option<&str> {
+                self.0.file_stem()
+            }
+
+            /// The extension of the final segment, without the leading `.`.
+            pub fn extension(&self) -> Option<&str> {
+                self.0.extension()
+            }
+
+            /// Returns a copy of `self` with the final segment replaced by `file_name`.
+            pub fn with_file_name(&self, file_name: &str) -> anyhow::Result<$dir> {
+                $dir(self.0.with_file_name(file_name)?).validate()
+            }
+
+            /// Returns a copy of `self` with the extension of the final segment
+            /// replaced by `extension`. An empty `extension` removes it.
+            pub fn with_extension(&self, extension: &str) -> anyhow::Result<$dir> {
+                $dir(self.0.with_extension(extension)?).validate()
+            }
+
+            /// Appends `path` (a single segment or a relative path), re-running
+            /// the same validation and `..`/`.` collapsing as `try_from`. The
+            /// appended segment decides the result's type: if its final
+            /// component has an extension, `self` becomes a `$file`, otherwise
+            /// it stays a `$dir` — "if it compiles it works" for building
+            /// paths up one segment at a time.
+            pub fn join(
+                &self,
+                path: impl $crate::inner::str_values::StrValues,
+            ) -> anyhow::Result<$crate::ops::Joined<$dir, $file>> {
+                let joined = self.0.join(path)?;
+                Ok(if joined.extension().is_some() {
+                    $crate::ops::Joined::File($file(joined).validate()?)
+                } else {
+                    $crate::ops::Joined::Dir($dir(joined).validate()?)
+                })
+            }
+
+            /// In-place version of [`Self::join`] for the common case where the
+            /// appended segment stays a directory.
+            pub fn push(&mut self, path: impl $crate::inner::str_values::StrValues) -> anyhow::Result<()> {
+                self.0.push(path)
+            }
+        }
+    };
+}
+
+/// Implements the file-typed manipulation surface: `parent`, `file_name`,
+/// `file_stem`, `extension`, `with_extension`, `with_file_name`, and a
+/// plain (non-trailing-separator) `Display`. The file-typed counterpart of
+/// [`dir_path!`] — there's no `join`/`push` here, since appending a segment
+/// to a file path doesn't make sense.
+#[macro_export]
+macro_rules! file_path {
+    ($file:ident, $dir:ident) => {
+        impl std::fmt::Display for $file {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                std::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl $file {
+            /// The enclosing directory, or `None` if this path has no parent segment.
+            pub fn parent(&self) -> Option<$dir> {
+                self.0.parent().and_then(|p| $dir(p).validate().ok())
+            }
+
+            /// The final segment of the path, if any.
+            pub fn file_name(&self) -> Option<&str> {
+                self.0.file_name()
+            }
+
+            /// The final segment without its extension.
+            pub fn file_stem(&self) -> Option<&str> {
+                self.0.file_stem()
+            }
+
+            /// The extension of the final segment, without the leading `.`.
+            pub fn extension(&self) -> Option<&str> {
+                self.0.extension()
+            }
+
+            /// Returns a copy of `self` with the extension of the final segment
+            /// replaced by `extension`. An empty `extension` removes it.
+            pub fn with_extension(&self, extension: &str) -> anyhow::Result<$file> {
+                $file(self.0.with_extension(extension)?).validate()
+            }
+
+            /// Returns a copy of `self` with the final segment replaced by `file_name`.
+            pub fn with_file_name(&self, file_name: &str) -> anyhow::Result<$file> {
+                $file(self.0.with_file_name(file_name)?).validate()
+            }
+        }
+    };
+}
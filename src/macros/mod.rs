@@ -0,0 +1,2 @@
+mod all_paths;
+mod try_from;
@@ -0,0 +1,150 @@
+//! Path manipulation that works purely on the resolved in-memory
+//! representation, without touching the filesystem. This mirrors the
+//! std [`Path`](std::path::Path) surface (`parent`, `file_name`,
+//! `file_stem`, `extension`, `join`, ...) on top of [`PathInner`].
+
+use anyhow::Result;
+
+use crate::inner::str_values::StrValues;
+use crate::inner::PathInner;
+use crate::os::OsGroup;
+use crate::SLASH;
+
+impl<OS: OsGroup> PathInner<OS> {
+    fn segment_vec(&self) -> Vec<&str> {
+        self.segments().collect()
+    }
+
+    fn from_segments(&self, segs: &[&str]) -> Result<Self> {
+        let sep = OS::SEP.to_string();
+        // `segments()` leaves out the leading separator(s) of an absolute
+        // path (`/`, or the `\\` of a UNC root), so put them back.
+        let lead = self.path.len() - self.path.trim_start_matches(OS::SEP).len();
+        Self::new(&format!("{}{}", &self.path[..lead.min(2)], segs.join(&sep)))
+    }
+
+    /// The enclosing directory, or `None` if this path has no parent segment.
+    pub(crate) fn parent(&self) -> Option<Self> {
+        let segs = self.segment_vec();
+        let parent_len = segs.len().checked_sub(1)?;
+        Some(self.from_segments(&segs[..parent_len]).expect("parent of a valid path is valid"))
+    }
+
+    /// The final segment of the path, if any.
+    pub(crate) fn file_name(&self) -> Option<&str> {
+        self.segment_vec().last().copied()
+    }
+
+    /// The final segment without its extension.
+    pub(crate) fn file_stem(&self) -> Option<&str> {
+        split_extension(self.file_name()?).0.into()
+    }
+
+    /// The extension of the final segment, without the leading `.`.
+    pub(crate) fn extension(&self) -> Option<&str> {
+        split_extension(self.file_name()?).1
+    }
+
+    /// Replaces the final segment, re-running validation on the result.
+    pub(crate) fn with_file_name(&self, file_name: &str) -> Result<Self> {
+        let mut segs = self.segment_vec();
+        segs.pop();
+        segs.push(file_name);
+        self.from_segments(&segs)
+    }
+
+    /// Replaces the extension of the final segment. An empty `extension`
+    /// removes it.
+    pub(crate) fn with_extension(&self, extension: &str) -> Result<Self> {
+        let mut segs = self.segment_vec();
+        let stem = split_extension(segs.pop().unwrap_or_default()).0;
+        let new_name = if extension.is_empty() {
+            stem.to_string()
+        } else {
+            format!("{stem}.{extension}")
+        };
+        segs.push(&new_name);
+        self.from_segments(&segs)
+    }
+
+    /// Appends `path` (a single segment or a relative path), re-running the
+    /// same validation and `..`/`.` collapsing as [`PathInner::new`].
+    pub(crate) fn join(&self, path: impl StrValues) -> Result<Self> {
+        let sep = OS::SEP.to_string();
+        let appended = path.join_strings(&sep);
+        if appended.is_empty() {
+            return Self::new(&self.path);
+        }
+        let mut joined = self.path.clone();
+        if !joined.is_empty() && !joined.ends_with(SLASH) {
+            joined.push(OS::SEP);
+        }
+        joined.push_str(&appended);
+        Self::new(&joined)
+    }
+
+    /// In-place version of [`PathInner::join`].
+    pub(crate) fn push(&mut self, path: impl StrValues) -> Result<()> {
+        *self = self.join(path)?;
+        Ok(())
+    }
+
+    /// The literal root prefix (`"/"`, `"c:"`, `"\\server\share\"`, or `""`
+    /// for a relative path), used to tell whether two absolute paths are
+    /// even rooted the same way (e.g. `c:\` vs `d:\`, or one UNC share vs
+    /// another) before walking their segments against each other.
+    pub(crate) fn root_prefix(&self) -> &str {
+        let mut chars = self.path.chars();
+        match (chars.next(), chars.next()) {
+            (Some(a), Some(b)) if a == OS::SEP && b == OS::SEP => {
+                // UNC root (`\\server\share\`): include the server and
+                // share so two different shares are never treated as the
+                // same root.
+                let after = &self.path[2..];
+                let share_end = after
+                    .char_indices()
+                    .filter(|(_, c)| *c == OS::SEP)
+                    .nth(1)
+                    .map(|(i, _)| i + 1)
+                    .unwrap_or(after.len());
+                &self.path[..2 + share_end]
+            }
+            (Some(c), Some(':')) if c.is_ascii_alphabetic() => &self.path[..2],
+            (Some(c), _) if c == OS::SEP => &self.path[..1],
+            _ => "",
+        }
+    }
+}
+
+/// Splits `name` on its final `.`, mirroring [`PathInner::file_stem`] /
+/// [`PathInner::extension`]. A leading dot (`.gitignore`) is not treated
+/// as an extension separator.
+fn split_extension(name: &str) -> (&str, Option<&str>) {
+    match name.rfind('.') {
+        Some(0) | None => (name, None),
+        Some(idx) => (&name[..idx], Some(&name[idx + 1..])),
+    }
+}
+
+/// Unicode lowercasing (`char::to_lowercase`) applied to a single path
+/// segment. Shared by `PathInner`'s `Eq`/`Ord`/`Hash` impls and
+/// the wrapper-level `relative_to`/`strip_prefix` helpers so they agree on
+/// what counts as "the same" segment.
+pub(crate) fn fold_segment(segment: &str) -> String {
+    segment.chars().flat_map(char::to_lowercase).collect()
+}
+
+/// Case-folded equality of two path segments, see [`fold_segment`].
+pub(crate) fn fold_segment_eq(a: &str, b: &str) -> bool {
+    fold_segment(a) == fold_segment(b)
+}
+
+/// The result of [`PathInner::join`]-style directory manipulation: a single
+/// appended segment can turn a directory-typed path into a file-typed one
+/// (when that segment has an extension), so the join methods generated by
+/// the `dir_path!` macro return this instead of picking one type and being
+/// wrong half the time.
+pub enum Joined<D, F> {
+    Dir(D),
+    File(F),
+}
@@ -0,0 +1,28 @@
+//! Per-platform behavior shared by every [`PathInner`](crate::inner::PathInner).
+//!
+//! This is the "property on the marker" that drives platform-specific
+//! rendering (the in-memory/native separator, `Debug` formatting) without
+//! any runtime inspection of the path itself.
+
+use std::fmt;
+
+pub(crate) trait OsGroup {
+    /// The in-memory and native path separator for this platform.
+    const SEP: char;
+
+    /// Formats `path` the way [`PathInner`](crate::inner::PathInner)'s
+    /// `Debug` impl should render it.
+    fn debug_fmt(path: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+/// The platform the binary actually runs on. The default `OS` parameter for
+/// every typed path wrapper.
+pub(crate) struct Native;
+
+impl OsGroup for Native {
+    const SEP: char = crate::SEP;
+
+    fn debug_fmt(path: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{path:?}")
+    }
+}
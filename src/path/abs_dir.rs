@@ -0,0 +1,73 @@
+use crate::{all_paths, dir_path, inner::PathInner, ops::fold_segment_eq, try_from};
+use anyhow::{ensure, Result};
+use serde::{Deserialize, Serialize};
+
+use super::abs_file::AbsFile;
+use super::rel_path::RelPath;
+
+/// An absolute directory path, resolved in memory but not checked against
+/// the filesystem.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(transparent)]
+pub struct AbsDir(pub(crate) PathInner);
+
+all_paths!(AbsDir);
+try_from!(AbsDir);
+dir_path!(AbsDir, AbsFile);
+
+impl AbsDir {
+    pub(crate) fn validate(self) -> Result<Self> {
+        ensure!(self.0.is_absolute(), "not an absolute path: {}", self.0);
+        Ok(self)
+    }
+
+    /// Expresses `self` relative to `base`, inserting a `..` segment for
+    /// every level `base` sits below the ancestor it shares with `self`,
+    /// followed by the rest of `self`'s segments. Errors if
+    /// `self` and `base` aren't rooted the same way (e.g. different drive
+    /// letters on Windows).
+    pub fn relative_to(&self, base: &AbsDir) -> Result<RelPath> {
+        ensure!(
+            self.0.root_prefix().eq_ignore_ascii_case(base.0.root_prefix()),
+            "{self} and {base} do not share a root"
+        );
+
+        let self_segs: Vec<&str> = self.0.segments().collect();
+        let base_segs: Vec<&str> = base.0.segments().collect();
+        let shared = common_prefix_len(&self_segs, &base_segs);
+
+        let mut segs: Vec<&str> = Vec::with_capacity(base_segs.len() - shared + self_segs.len());
+        segs.extend(std::iter::repeat("..").take(base_segs.len() - shared));
+        segs.extend_from_slice(&self_segs[shared..]);
+
+        // Set directly rather than re-parsed: `PathInner::new` cancels a
+        // segment followed by `..`, which would eat consecutive `..` segments.
+        let mut rel = RelPath::try_from("")?;
+        rel.0.path = segs.join(&crate::SEP.to_string());
+        Ok(rel)
+    }
+
+    /// The portion of `self` after `base`. Unlike [`AbsDir::relative_to`],
+    /// this never inserts `..` segments: it errors if `self` isn't nested
+    /// inside `base`.
+    pub fn strip_prefix(&self, base: &AbsDir) -> Result<RelPath> {
+        let self_segs: Vec<&str> = self.0.segments().collect();
+        let base_segs: Vec<&str> = base.0.segments().collect();
+        ensure!(
+            self.0.root_prefix().eq_ignore_ascii_case(base.0.root_prefix())
+                && common_prefix_len(&self_segs, &base_segs) == base_segs.len(),
+            "{self} is not inside {base}"
+        );
+
+        RelPath::try_from(self_segs[base_segs.len()..].join("/").as_str())
+    }
+}
+
+/// The number of leading segments `a` and `b` have in common, compared
+/// case-folded the same way as [`PathInner`]'s `Eq`/`Ord`/`Hash` impls.
+fn common_prefix_len(a: &[&str], b: &[&str]) -> usize {
+    a.iter()
+        .zip(b.iter())
+        .take_while(|(x, y)| fold_segment_eq(x, y))
+        .count()
+}
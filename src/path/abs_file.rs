@@ -0,0 +1,21 @@
+use crate::{all_paths, file_path, inner::PathInner, try_from};
+use anyhow::{ensure, Result};
+use serde::{Deserialize, Serialize};
+
+use super::abs_dir::AbsDir;
+
+/// An absolute file path. The file-typed counterpart of [`AbsDir`](crate::AbsDir).
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(transparent)]
+pub struct AbsFile(pub(crate) PathInner);
+
+all_paths!(AbsFile);
+try_from!(AbsFile);
+file_path!(AbsFile, AbsDir);
+
+impl AbsFile {
+    pub(crate) fn validate(self) -> Result<Self> {
+        ensure!(self.0.is_absolute(), "not an absolute path: {}", self.0);
+        Ok(self)
+    }
+}
@@ -1,13 +1,16 @@
-use crate::{all_paths, inner::PathInner, try_from};
+use crate::{all_paths, dir_path, inner::PathInner, try_from};
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Serialize, Deserialize)]
+use super::any_file::AnyFile;
+
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[serde(transparent)]
 pub struct AnyDir(pub(crate) PathInner);
 
 all_paths!(AnyDir);
 try_from!(AnyDir);
+dir_path!(AnyDir, AnyFile);
 
 impl AnyDir {
     pub(crate) fn validate(self) -> Result<Self> {
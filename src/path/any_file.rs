@@ -0,0 +1,21 @@
+use crate::{all_paths, file_path, inner::PathInner, try_from};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use super::any_dir::AnyDir;
+
+/// A file path, absolute or relative, resolved in memory but not checked
+/// against the filesystem. The file-typed counterpart of [`AnyDir`](crate::AnyDir).
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(transparent)]
+pub struct AnyFile(pub(crate) PathInner);
+
+all_paths!(AnyFile);
+try_from!(AnyFile);
+file_path!(AnyFile, AnyDir);
+
+impl AnyFile {
+    pub(crate) fn validate(self) -> Result<Self> {
+        Ok(self)
+    }
+}
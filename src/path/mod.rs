@@ -0,0 +1,33 @@
+mod abs_dir;
+mod abs_file;
+mod any_dir;
+mod any_file;
+mod rel_path;
+
+pub use abs_dir::AbsDir;
+pub use abs_file::AbsFile;
+pub use any_dir::AnyDir;
+pub use any_file::AnyFile;
+pub use rel_path::RelPath;
+
+/// Umbrella trait for the crate's typed path wrappers, used where a
+/// function accepts any of them (e.g. [`AnyDir`], [`AbsDir`]) without
+/// caring which.
+pub trait AnyPath: AsRef<std::path::Path> {
+    /// The segments of the path, in order, after `.`/`..` collapsing.
+    fn segments(&self) -> Vec<&str>;
+}
+
+macro_rules! impl_any_path {
+    ($($struct:ident),* $(,)?) => {
+        $(
+            impl AnyPath for $struct {
+                fn segments(&self) -> Vec<&str> {
+                    $struct::segments(self).collect()
+                }
+            }
+        )*
+    };
+}
+
+impl_any_path!(AnyDir, AnyFile, AbsDir, AbsFile, RelPath);
@@ -0,0 +1,28 @@
+use crate::{all_paths, inner::PathInner, try_from};
+use anyhow::{ensure, Result};
+use serde::{Deserialize, Serialize};
+
+/// A relative path, i.e. one that needs a base directory to be resolved
+/// against the filesystem. The type returned by [`AbsDir::relative_to`](crate::AbsDir::relative_to)
+/// and [`AbsDir::strip_prefix`](crate::AbsDir::strip_prefix), since the
+/// result of expressing one absolute path in terms of another is neither
+/// reliably a directory nor a file.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(transparent)]
+pub struct RelPath(pub(crate) PathInner);
+
+all_paths!(RelPath);
+try_from!(RelPath);
+
+impl std::fmt::Display for RelPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl RelPath {
+    pub(crate) fn validate(self) -> Result<Self> {
+        ensure!(!self.0.is_absolute(), "not a relative path: {}", self.0);
+        Ok(self)
+    }
+}
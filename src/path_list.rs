@@ -0,0 +1,54 @@
+//! Parsing and building OS `PATH`-style variable lists (`PATH`,
+//! `LD_LIBRARY_PATH`, ...) into typed, validated path vectors, paralleling
+//! [`std::env::split_paths`]/[`std::env::join_paths`].
+
+use anyhow::{bail, Result};
+
+use crate::path::AbsDir;
+use crate::SEP;
+
+const LIST_SEP: char = if SEP == '\\' { ';' } else { ':' };
+
+/// How [`split_paths`] treats empty entries in the list (e.g. back-to-back
+/// separators or a leading/trailing one), which commonly show up in
+/// hand-edited environment variables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmptyEntries {
+    /// Empty entries are silently dropped.
+    Skip,
+    /// Empty entries return an error.
+    Error,
+}
+
+/// Splits `value` on the platform list separator (`:` on Unix, `;` on
+/// Windows), resolving and validating each entry through the same pipeline
+/// as [`AbsDir::try_from`]. Entries are always [`AbsDir`]: a relative entry
+/// in a `PATH`-style variable is meaningless without knowing what it's
+/// relative to, so `split_paths` rejects it rather than guessing.
+pub fn split_paths(value: &str, empty: EmptyEntries) -> Result<Vec<AbsDir>> {
+    let mut dirs = Vec::new();
+    for entry in value.split(LIST_SEP) {
+        if entry.is_empty() {
+            match empty {
+                EmptyEntries::Skip => continue,
+                EmptyEntries::Error => bail!("empty entry in path list: {value}"),
+            }
+        }
+        dirs.push(AbsDir::try_from(entry)?);
+    }
+    Ok(dirs)
+}
+
+/// Renders `paths` back into a single, native-separator, list-separator
+/// joined string. The inverse of [`split_paths`]. Each entry is rendered
+/// via the resolved inner path directly (not `AbsDir`'s own `Display`,
+/// which appends a trailing separator for directory-typed paths) so the
+/// list separator itself isn't swallowed by it, e.g. `/usr/bin:/usr/local/bin`
+/// rather than `/usr/bin/:/usr/local/bin/`.
+pub fn join_paths(paths: &[AbsDir]) -> String {
+    paths
+        .iter()
+        .map(|dir| format!("{:#}", dir.0))
+        .collect::<Vec<_>>()
+        .join(&LIST_SEP.to_string())
+}
@@ -0,0 +1,236 @@
+//! Normalization of Windows UNC and extended-length (`\\?\`) path prefixes.
+//!
+//! Modeled on the approach used by the [`dunce`](https://crates.io/crates/dunce)
+//! crate: every prefix shape is parsed eagerly into a canonical in-memory
+//! form, but on output we prefer the most compatible legacy spelling unless
+//! some component actually requires the verbatim form.
+
+use crate::SLASH;
+
+/// The four Windows path-prefix shapes recognized when resolving a path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum WindowsPrefix {
+    /// `C:\...` (`rooted: true`) or the drive-relative `C:...` (`rooted: false`).
+    Drive { letter: char, rooted: bool },
+    /// `\\server\share\...`
+    Unc { server: String, share: String },
+    /// `\\?\C:\...` (`rooted: true`) or `\\?\C:...` (`rooted: false`).
+    VerbatimDisk { letter: char, rooted: bool },
+    /// `\\?\UNC\server\share\...`
+    VerbatimUnc { server: String, share: String },
+}
+
+impl WindowsPrefix {
+    /// Whether this prefix was spelled in its verbatim (`\\?\`) form.
+    pub(crate) fn is_verbatim(&self) -> bool {
+        matches!(
+            self,
+            WindowsPrefix::VerbatimDisk { .. } | WindowsPrefix::VerbatimUnc { .. }
+        )
+    }
+
+    /// Length in characters of the rendered legacy root (e.g. `"c:\"` or
+    /// `"\\server\share\"`), used for the Windows 260-character check.
+    fn root_len(&self) -> usize {
+        match self {
+            WindowsPrefix::Drive { .. } | WindowsPrefix::VerbatimDisk { .. } => 3,
+            WindowsPrefix::Unc { server, share } | WindowsPrefix::VerbatimUnc { server, share } => {
+                server.len() + share.len() + 4
+            }
+        }
+    }
+}
+
+/// Recognizes and strips one of the four Windows prefix shapes from the
+/// start of `path`, returning the parsed prefix and the unparsed remainder.
+/// Returns `None` when `path` doesn't start with any of them.
+pub(crate) fn parse_windows_prefix(path: &str) -> Option<(WindowsPrefix, &str)> {
+    if let Some(rest) = strip_any(path, r"\\?\UNC\", "//?/UNC/") {
+        let (server, share, rest) = split_server_share(rest)?;
+        return Some((WindowsPrefix::VerbatimUnc { server, share }, rest));
+    }
+    if let Some(rest) = strip_any(path, r"\\?\", "//?/") {
+        let (letter, rooted, rest) = split_drive(rest)?;
+        return Some((WindowsPrefix::VerbatimDisk { letter, rooted }, rest));
+    }
+    if let Some(rest) = strip_any(path, r"\\", "//") {
+        let (server, share, rest) = split_server_share(rest)?;
+        return Some((WindowsPrefix::Unc { server, share }, rest));
+    }
+    let (letter, rooted, rest) = split_drive(path)?;
+    Some((WindowsPrefix::Drive { letter, rooted }, rest))
+}
+
+fn strip_any<'a>(path: &'a str, win: &str, alt: &str) -> Option<&'a str> {
+    path.strip_prefix(win).or_else(|| path.strip_prefix(alt))
+}
+
+/// Splits a `C:...` prefix off `rest`, reporting whether the drive letter
+/// was followed by a root separator (`C:\dir`, drive-absolute) or not
+/// (`C:dir`, drive-relative) — the two resolve very differently and must
+/// not be conflated.
+fn split_drive(rest: &str) -> Option<(char, bool, &str)> {
+    let mut chars = rest.chars();
+    let drive = chars.next().filter(char::is_ascii_alphabetic)?;
+    if chars.next() != Some(':') {
+        return None;
+    }
+    let after_colon = &rest[2..];
+    let rooted = after_colon.starts_with(SLASH);
+    let rest = after_colon.strip_prefix(SLASH).unwrap_or(after_colon);
+    Some((drive, rooted, rest))
+}
+
+fn split_server_share(rest: &str) -> Option<(String, String, &str)> {
+    let mut parts = rest.splitn(3, SLASH);
+    let server = parts.next().filter(|s| !s.is_empty())?;
+    let share = parts.next().filter(|s| !s.is_empty())?;
+    let remainder = parts.next().unwrap_or("");
+    Some((server.to_string(), share.to_string(), remainder))
+}
+
+const RESERVED_NAMES: [&str; 24] = [
+    "CON", "PRN", "AUX", "NUL", "COM0", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7",
+    "COM8", "COM9", "LPT0", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+fn is_reserved_name(segment: &str) -> bool {
+    let stem = segment.split('.').next().unwrap_or(segment);
+    RESERVED_NAMES.iter().any(|r| stem.eq_ignore_ascii_case(r))
+}
+
+/// True if any segment of `rest` forces the verbatim `\\?\` spelling on
+/// output: a reserved device name, a trailing dot/space, a component over
+/// 255 characters, or a total legacy-form length over 260 characters.
+pub(crate) fn needs_verbatim_form(prefix: &WindowsPrefix, rest: &str) -> bool {
+    if prefix.root_len() + rest.len() > 260 {
+        return true;
+    }
+    rest.split(SLASH).any(|seg| {
+        seg.len() > 255 || seg.ends_with('.') || seg.ends_with(' ') || is_reserved_name(seg)
+    })
+}
+
+/// Renders the root prefix for output, preferring the legacy form
+/// (`c:\...`, `\\server\share\...`) and falling back to the verbatim form
+/// only when [`needs_verbatim_form`] requires it.
+pub(crate) fn render_windows_root(prefix: &WindowsPrefix, rest: &str, sep: char) -> String {
+    let keep_verbatim = prefix.is_verbatim() && needs_verbatim_form(prefix, rest);
+    match (prefix, keep_verbatim) {
+        (WindowsPrefix::Drive { letter, .. } | WindowsPrefix::VerbatimDisk { letter, .. }, false) => {
+            format!("{}:{sep}", letter.to_ascii_lowercase())
+        }
+        (WindowsPrefix::Drive { letter, .. } | WindowsPrefix::VerbatimDisk { letter, .. }, true) => {
+            format!(r"\\?\{}:{sep}", letter.to_ascii_lowercase())
+        }
+        (WindowsPrefix::Unc { server, share } | WindowsPrefix::VerbatimUnc { server, share }, false) => {
+            format!("{sep}{sep}{server}{sep}{share}{sep}")
+        }
+        (WindowsPrefix::Unc { server, share } | WindowsPrefix::VerbatimUnc { server, share }, true) => {
+            format!(r"\\?\UNC\{server}\{share}{sep}")
+        }
+    }
+}
+
+/// The non-Windows resolution of a Windows prefix: drive letters are
+/// dropped (consistent with the existing resolution table), and UNC shares
+/// become a `/server/share` root. Share-root paths are always absolute,
+/// never relative. A drive-absolute path (`C:\dir`) stays absolute (`/dir`);
+/// a drive-relative one (`c:dir`) has no root here and resolves like any
+/// other relative path.
+pub(crate) fn non_windows_root_segment(prefix: &WindowsPrefix) -> Option<String> {
+    match prefix {
+        WindowsPrefix::Drive { rooted, .. } | WindowsPrefix::VerbatimDisk { rooted, .. } => {
+            rooted.then(|| "/".to_string())
+        }
+        WindowsPrefix::Unc { server, share } | WindowsPrefix::VerbatimUnc { server, share } => {
+            Some(format!("/{server}/{share}"))
+        }
+    }
+}
+
+#[test]
+fn test_parse_windows_prefix() {
+    assert_eq!(
+        parse_windows_prefix(r"C:\dir"),
+        Some((
+            WindowsPrefix::Drive {
+                letter: 'C',
+                rooted: true
+            },
+            "dir"
+        ))
+    );
+    assert_eq!(
+        parse_windows_prefix(r"C:dir"),
+        Some((
+            WindowsPrefix::Drive {
+                letter: 'C',
+                rooted: false
+            },
+            "dir"
+        ))
+    );
+    assert_eq!(
+        parse_windows_prefix(r"\\server\share\dir"),
+        Some((
+            WindowsPrefix::Unc {
+                server: "server".into(),
+                share: "share".into()
+            },
+            "dir"
+        ))
+    );
+    assert_eq!(
+        parse_windows_prefix(r"\\?\C:\dir"),
+        Some((
+            WindowsPrefix::VerbatimDisk {
+                letter: 'C',
+                rooted: true
+            },
+            "dir"
+        ))
+    );
+    assert_eq!(
+        parse_windows_prefix(r"\\?\UNC\server\share\dir"),
+        Some((
+            WindowsPrefix::VerbatimUnc {
+                server: "server".into(),
+                share: "share".into()
+            },
+            "dir"
+        ))
+    );
+    assert_eq!(parse_windows_prefix("dir/sub"), None);
+}
+
+#[test]
+fn test_needs_verbatim_form() {
+    let disk = WindowsPrefix::VerbatimDisk {
+        letter: 'C',
+        rooted: true,
+    };
+    assert!(!needs_verbatim_form(&disk, "dir"));
+    assert!(needs_verbatim_form(&disk, "con"));
+    assert!(needs_verbatim_form(&disk, "trailing."));
+    assert!(needs_verbatim_form(&disk, &"a".repeat(256)));
+    assert!(needs_verbatim_form(&disk, &"dir/".repeat(90)));
+}
+
+#[test]
+fn test_non_windows_root_segment_drive_absoluteness() {
+    assert_eq!(
+        non_windows_root_segment(&WindowsPrefix::Drive {
+            letter: 'C',
+            rooted: true
+        }),
+        Some("/".to_string())
+    );
+    assert_eq!(
+        non_windows_root_segment(&WindowsPrefix::Drive {
+            letter: 'C',
+            rooted: false
+        }),
+        None
+    );
+}
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use serde::{Deserialize, Serialize};
 use x_path::AbsDir;
 
@@ -49,6 +51,36 @@ fn itest_abs_dir() {
     assert_eq!(val, "not a directory: ./Cargo.toml at line 1 column 26");
 }
 
+#[test]
+fn itest_abs_dir_case_folded_eq_and_hash() {
+    let lower = AbsDir::try_from("/Dir1/Dir2").unwrap();
+    let upper = AbsDir::try_from("/DIR1/DIR2").unwrap();
+
+    assert_eq!(lower, upper);
+
+    let mut set = HashSet::new();
+    set.insert(lower);
+    assert!(set.contains(&upper));
+}
+
+#[test]
+fn itest_abs_dir_relative_to() {
+    let dir = AbsDir::try_from("/a/b/c").unwrap();
+    let base = AbsDir::try_from("/a/x/y").unwrap();
+
+    assert_eq!(dir.relative_to(&base).unwrap().to_string(), "../../b/c");
+    assert_eq!(base.relative_to(&dir).unwrap().to_string(), "../../x/y");
+}
+
+#[test]
+fn itest_abs_dir_strip_prefix() {
+    let dir = AbsDir::try_from("/a/b/c").unwrap();
+    let base = AbsDir::try_from("/a/b").unwrap();
+
+    assert_eq!(dir.strip_prefix(&base).unwrap().to_string(), "c");
+    assert!(base.strip_prefix(&dir).is_err());
+}
+
 fn err_json(s: &str) -> String {
     serde_json::from_str::<PathTest>(s)
         .map_err(|e| e.to_string())
@@ -0,0 +1,62 @@
+use std::collections::HashSet;
+
+use x_path::{AnyDir, Joined};
+
+#[test]
+fn itest_any_dir_manipulation() {
+    let dir = AnyDir::try_from("dir1/dir2").unwrap();
+
+    assert_eq!(dir.file_name(), Some("dir2"));
+    assert_eq!(dir.file_stem(), Some("dir2"));
+    assert_eq!(dir.extension(), None);
+    assert_eq!(dir.parent().unwrap().to_string(), "dir1/");
+
+    let renamed = dir.with_file_name("dir3").unwrap();
+    assert_eq!(renamed.to_string(), "dir1/dir3/");
+
+    let extended = dir.with_extension("bak").unwrap();
+    assert_eq!(extended.extension(), Some("bak"));
+}
+
+#[test]
+fn itest_any_dir_join_changes_type_with_extension() {
+    let dir = AnyDir::try_from("dir1").unwrap();
+
+    match dir.join("dir2").unwrap() {
+        Joined::Dir(d) => assert_eq!(d.to_string(), "dir1/dir2/"),
+        Joined::File(_) => panic!("expected a dir"),
+    }
+
+    match dir.join("file.txt").unwrap() {
+        Joined::File(f) => assert_eq!(f.to_string(), "dir1/file.txt"),
+        Joined::Dir(_) => panic!("expected a file"),
+    }
+}
+
+#[test]
+fn itest_any_dir_push() {
+    let mut dir = AnyDir::try_from("dir1").unwrap();
+    dir.push("dir2").unwrap();
+    assert_eq!(dir.to_string(), "dir1/dir2/");
+}
+
+#[test]
+fn itest_any_dir_display_trailing_separator() {
+    let dir = AnyDir::try_from("dir1/dir2").unwrap();
+    assert_eq!(dir.to_string(), "dir1/dir2/");
+
+    let root = AnyDir::try_from("/").unwrap();
+    assert_eq!(root.to_string(), "/");
+}
+
+#[test]
+fn itest_any_dir_case_folded_eq_and_hash() {
+    let lower = AnyDir::try_from("Dir1/Dir2").unwrap();
+    let upper = AnyDir::try_from("DIR1/DIR2").unwrap();
+
+    assert_eq!(lower, upper);
+
+    let mut set = HashSet::new();
+    set.insert(lower);
+    assert!(set.contains(&upper));
+}
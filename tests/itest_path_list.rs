@@ -0,0 +1,33 @@
+use x_path::{join_paths, split_paths, AbsDir, EmptyEntries};
+
+#[test]
+fn itest_split_and_join_paths() {
+    let dirs = split_paths("/usr/bin:/usr/local/bin", EmptyEntries::Skip).unwrap();
+    assert_eq!(
+        dirs,
+        vec![
+            AbsDir::try_from("/usr/bin").unwrap(),
+            AbsDir::try_from("/usr/local/bin").unwrap(),
+        ]
+    );
+
+    assert_eq!(join_paths(&dirs), "/usr/bin:/usr/local/bin");
+}
+
+#[test]
+fn itest_split_paths_empty_entries() {
+    assert_eq!(
+        split_paths("/usr/bin::/usr/local/bin", EmptyEntries::Skip).unwrap(),
+        vec![
+            AbsDir::try_from("/usr/bin").unwrap(),
+            AbsDir::try_from("/usr/local/bin").unwrap(),
+        ]
+    );
+
+    assert!(split_paths("/usr/bin::/usr/local/bin", EmptyEntries::Error).is_err());
+}
+
+#[test]
+fn itest_split_paths_rejects_relative_entries() {
+    assert!(split_paths("bin:/usr/bin", EmptyEntries::Skip).is_err());
+}